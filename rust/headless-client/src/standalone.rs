@@ -16,13 +16,61 @@ use firezone_bin_shared::{
 use futures::{FutureExt as _, StreamExt as _};
 use phoenix_channel::PhoenixChannel;
 use secrecy::{Secret, SecretString};
+use serde::{Deserialize, Serialize};
 use std::{
     path::{Path, PathBuf},
     pin::pin,
     sync::Arc,
 };
-use tokio::{sync::mpsc, time::Instant};
+use tokio::{
+    sync::{mpsc, oneshot},
+    time::Instant,
+};
 use tokio_stream::wrappers::ReceiverStream;
+use tracing_subscriber::{reload, EnvFilter, Layer as _};
+
+/// Default filter applied at startup, reloadable afterwards via SIGUSR1 or the control socket
+const DEFAULT_LOG_FILTER: &str =
+    "firezone_headless_client=info,connlib_client_shared=info,phoenix_channel=info,dns_control=info";
+
+/// Stable process exit codes so supervisors and health scripts can react to each failure
+/// class differently, instead of treating every non-zero exit the same
+mod exit_code {
+    /// The token wasn't found in the env var or at the token path (from `--token-path`,
+    /// the config file, or the default)
+    pub(super) const TOKEN_NOT_FOUND: i32 = 2;
+    /// The portal rejected the token while establishing the initial connection
+    pub(super) const AUTH_FAILURE: i32 = 3;
+    /// `--connect-timeout` elapsed before the initial connection completed
+    pub(super) const CONNECT_TIMEOUT: i32 = 4;
+}
+
+/// Default path for the optional TOML config file, used if `--config` is not given
+fn default_config_path() -> PathBuf {
+    if cfg!(target_os = "windows") {
+        PathBuf::from(r"C:\ProgramData\dev.firezone.client\config.toml")
+    } else {
+        PathBuf::from("/etc/firezone/client.toml")
+    }
+}
+
+/// Default path for the control socket / named pipe, used if `--control-socket` is not given
+fn default_control_socket_path() -> PathBuf {
+    if cfg!(target_os = "windows") {
+        PathBuf::from(r"\\.\pipe\dev.firezone.client")
+    } else {
+        PathBuf::from("/var/run/firezone-client.sock")
+    }
+}
+
+/// Default path for the runtime log filter file, used if `--log-filter-file` is not given
+fn default_log_filter_file_path() -> PathBuf {
+    if cfg!(target_os = "windows") {
+        PathBuf::from(r"C:\ProgramData\dev.firezone.client\log-filter")
+    } else {
+        PathBuf::from("/etc/firezone/log-filter")
+    }
+}
 
 /// Command-line args for the headless Client
 #[derive(clap::Parser)]
@@ -36,14 +84,48 @@ struct Cli {
     #[command(flatten)]
     common: CliCommon,
 
-    #[arg(
-        short = 'u',
-        long,
-        hide = true,
-        env = "FIREZONE_API_URL",
-        default_value = "wss://api.firezone.dev"
-    )]
-    api_url: url::Url,
+    /// Path to a TOML config file
+    ///
+    /// Values here are overridden by CLI flags and environment variables, in that
+    /// order, and fall back to built-in defaults if neither is set.
+    #[arg(long, default_value = default_config_path().display().to_string())]
+    config: PathBuf,
+
+    /// Path to the control socket (Unix domain socket on Linux/macOS, named pipe on Windows)
+    #[arg(long, default_value = default_control_socket_path().display().to_string())]
+    control_socket: PathBuf,
+
+    /// Path to a file containing an `EnvFilter` string, re-read on `SIGUSR1`
+    ///
+    /// Lets operators turn up e.g. `connlib`/`phoenix_channel`/`dns_control` tracing on a
+    /// live node without a disruptive reconnect, then turn it back down the same way.
+    #[arg(long, default_value = default_log_filter_file_path().display().to_string())]
+    log_filter_file: PathBuf,
+
+    /// Timeout for the initial connection to the portal
+    ///
+    /// Unlike `--max-partition-time`, which only governs reconnection once connected,
+    /// this bounds how long we'll wait for the very first `OnSetInterfaceConfig`. On
+    /// expiry the process exits with a dedicated non-zero code rather than hanging.
+    #[arg(long)]
+    connect_timeout: Option<humantime::Duration>,
+
+    /// Path to a PEM client certificate chain, for mutual TLS to the portal
+    #[arg(long, requires = "client_key")]
+    client_cert: Option<PathBuf>,
+
+    /// Path to the PEM private key matching `--client-cert`
+    #[arg(long, requires = "client_cert")]
+    client_key: Option<PathBuf>,
+
+    /// Path to a PEM root certificate to trust instead of the default webpki roots
+    ///
+    /// For deployments that gate portal access behind a private or air-gapped PKI.
+    #[arg(long)]
+    ca_cert: Option<PathBuf>,
+
+    #[arg(short = 'u', long, hide = true, env = "FIREZONE_API_URL")]
+    api_url: Option<url::Url>,
 
     /// Check the configuration and return 0 before connecting to the API
     ///
@@ -81,8 +163,264 @@ struct Cli {
     // until anyone asks for it, env vars are okay and files on disk are slightly better.
     // (Since we run as root and the env var on a headless system is probably stored
     // on disk somewhere anyway.)
-    #[arg(default_value = default_token_path().display().to_string(), env = "FIREZONE_TOKEN_PATH", long)]
-    token_path: PathBuf,
+    #[arg(env = "FIREZONE_TOKEN_PATH", long)]
+    token_path: Option<PathBuf>,
+}
+
+/// On-disk config, deserialized from the TOML file at [`Cli::config`]
+///
+/// Every field is optional: a CLI flag or environment variable for the same
+/// setting always wins, and fields left out here fall back to [`Cli`]'s
+/// built-in defaults.
+#[derive(Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct ConfigFile {
+    api_url: Option<url::Url>,
+    firezone_id: Option<String>,
+    firezone_name: Option<String>,
+    token_path: Option<PathBuf>,
+    max_partition_time: Option<humantime::Duration>,
+    log_dir: Option<PathBuf>,
+    dns_control_method: Option<String>,
+    client_cert: Option<PathBuf>,
+    client_key: Option<PathBuf>,
+    ca_cert: Option<PathBuf>,
+}
+
+/// Read and parse the config file, if it exists
+///
+/// It's normal for the default config path to not exist, so a missing file
+/// at the default path is not an error. A missing file at an explicitly-given
+/// `--config` path, or a file that fails to parse, is.
+fn load_config_file(path: &Path, is_default_path: bool) -> Result<ConfigFile> {
+    let text = match std::fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(error) if is_default_path && error.kind() == std::io::ErrorKind::NotFound => {
+            return Ok(ConfigFile::default())
+        }
+        Err(error) => {
+            return Err(error).with_context(|| format!("Couldn't read config file `{}`", path.display()))
+        }
+    };
+
+    toml::from_str(&text)
+        .with_context(|| format!("Config file `{}` is malformed", path.display()))
+}
+
+/// Build the rustls `ClientConfig` used for the `wss://` connection to the portal, once
+///
+/// Returns `None` if none of `--client-cert`/`--client-key`/`--ca-cert` were given, so
+/// callers fall back to the default, server-verification-only config. The same config is
+/// reused for the initial connect and every reconnect, so mTLS client certs and a pinned
+/// CA only need to be parsed once per run.
+fn build_tls_client_config(
+    client_cert: Option<&Path>,
+    client_key: Option<&Path>,
+    ca_cert: Option<&Path>,
+) -> Result<Option<Arc<rustls::ClientConfig>>> {
+    if client_cert.is_none() && client_key.is_none() && ca_cert.is_none() {
+        return Ok(None);
+    }
+
+    let mut roots = rustls::RootCertStore::empty();
+    match ca_cert {
+        Some(ca_cert) => {
+            for cert in load_pem_certs(ca_cert)? {
+                roots
+                    .add(cert)
+                    .with_context(|| format!("Invalid `--ca-cert` `{}`", ca_cert.display()))?;
+            }
+        }
+        None => roots.extend(webpki_roots::TLS_SERVER_ROOTS.iter().cloned()),
+    }
+    let builder = rustls::ClientConfig::builder().with_root_certificates(roots);
+
+    let config = match (client_cert, client_key) {
+        (Some(cert_path), Some(key_path)) => builder
+            .with_client_auth_cert(load_pem_certs(cert_path)?, load_pem_private_key(key_path)?)
+            .context("Invalid `--client-cert`/`--client-key`")?,
+        (None, None) => builder.with_no_client_auth(),
+        // `requires` on the `clap` args should already enforce this, but don't rely on it.
+        _ => return Err(anyhow!("`--client-cert` and `--client-key` must be given together")),
+    };
+
+    Ok(Some(Arc::new(config)))
+}
+
+/// Connect to the portal and start a `Session`, for the initial connect and every reconnect
+///
+/// The mTLS `ClientConfig` is baked into `crate::tcp_socket_factory`'s returned closure here,
+/// rather than threaded through `PhoenixChannel::connect` as its own parameter, so the portal
+/// socket factory is the single place client certs / a pinned CA get applied. Factored out
+/// because the connect sequence (rebuild `LoginUrl` -> `PhoenixChannel::connect` ->
+/// `ConnectArgs` -> `Session::connect`) previously got copy-pasted at every reconnect site,
+/// which is exactly the kind of thing that drifts when one copy gets patched and the others
+/// don't.
+fn connect_portal(
+    url: LoginUrl,
+    max_partition_time: Option<std::time::Duration>,
+    tls_client_config: Option<Arc<rustls::ClientConfig>>,
+    private_key: connlib_client_shared::StaticSecret,
+    callbacks: CallbackHandler,
+    rt_handle: tokio::runtime::Handle,
+) -> Result<Session> {
+    let portal = PhoenixChannel::connect(
+        Secret::new(url),
+        get_user_agent(None, env!("CARGO_PKG_VERSION")),
+        "client",
+        (),
+        ExponentialBackoffBuilder::default()
+            .with_max_elapsed_time(max_partition_time)
+            .build(),
+        Arc::new(crate::tcp_socket_factory(tls_client_config)),
+    )?;
+    let args = ConnectArgs {
+        udp_socket_factory: Arc::new(crate::udp_socket_factory),
+        tcp_socket_factory: Arc::new(crate::tcp_socket_factory(None)),
+        private_key,
+        callbacks,
+    };
+
+    Ok(Session::connect(args, portal, rt_handle))
+}
+
+fn load_pem_certs(path: &Path) -> Result<Vec<rustls::pki_types::CertificateDer<'static>>> {
+    let file =
+        std::fs::File::open(path).with_context(|| format!("Couldn't open `{}`", path.display()))?;
+    rustls_pemfile::certs(&mut std::io::BufReader::new(file))
+        .collect::<Result<Vec<_>, _>>()
+        .with_context(|| format!("Couldn't parse PEM certs from `{}`", path.display()))
+}
+
+fn load_pem_private_key(path: &Path) -> Result<rustls::pki_types::PrivateKeyDer<'static>> {
+    let file =
+        std::fs::File::open(path).with_context(|| format!("Couldn't open `{}`", path.display()))?;
+    rustls_pemfile::private_key(&mut std::io::BufReader::new(file))
+        .with_context(|| format!("Couldn't parse a PEM private key from `{}`", path.display()))?
+        .ok_or_else(|| anyhow!("No private key found in `{}`", path.display()))
+}
+
+/// A command read from the control socket, one JSON object per line
+#[derive(Deserialize)]
+#[serde(tag = "cmd", rename_all = "kebab-case")]
+enum ControlRequest {
+    Status,
+    Reset,
+    ReloadToken,
+    Disconnect,
+    SetLogFilter { filter: String },
+}
+
+/// A [`ControlRequest`], forwarded from a control-socket connection to the main select loop
+enum ControlCommand {
+    Status(oneshot::Sender<StatusResponse>),
+    Reset,
+    ReloadToken,
+    Disconnect,
+    SetLogFilter(String),
+}
+
+/// Reply to a `status` control command
+#[derive(Serialize)]
+struct StatusResponse {
+    connected: bool,
+    startup_elapsed_secs: Option<f64>,
+    resource_count: usize,
+    dns_control_method: String,
+}
+
+/// Reply to a control command that failed to parse or apply
+#[derive(Serialize)]
+struct ErrorResponse {
+    error: String,
+}
+
+/// Accept connections on the control socket and forward parsed commands to the main loop
+///
+/// Runs until it hits an unrecoverable error; a malformed request on one connection
+/// doesn't bring down the listener.
+async fn run_control_listener(path: PathBuf, tx: mpsc::Sender<ControlCommand>) -> Result<()> {
+    #[cfg(unix)]
+    {
+        // Remove a stale socket left behind by a previous, uncleanly-terminated run.
+        let _ = std::fs::remove_file(&path);
+
+        // Narrow the umask around `bind` so the socket is created owner-only
+        // *atomically*: a post-hoc `set_permissions` would leave a window between
+        // `bind` returning and the chmod landing where a local user could race to
+        // connect against this root-owned VPN client.
+        let previous_umask = rustix::process::umask(rustix::fs::Mode::from_raw_mode(0o077));
+        let bind_result = tokio::net::UnixListener::bind(&path);
+        rustix::process::umask(previous_umask);
+        let listener =
+            bind_result.with_context(|| format!("Couldn't bind control socket `{}`", path.display()))?;
+
+        loop {
+            let (stream, _addr) = listener.accept().await?;
+            tokio::spawn(handle_control_connection(stream, tx.clone()));
+        }
+    }
+
+    #[cfg(windows)]
+    {
+        use tokio::net::windows::named_pipe::ServerOptions;
+
+        let mut first_instance = true;
+        loop {
+            let server = ServerOptions::new()
+                .first_pipe_instance(first_instance)
+                .create(&path)
+                .with_context(|| format!("Couldn't create control pipe `{}`", path.display()))?;
+            first_instance = false;
+            server.connect().await?;
+            tokio::spawn(handle_control_connection(server, tx.clone()));
+        }
+    }
+}
+
+async fn handle_control_connection<S>(stream: S, tx: mpsc::Sender<ControlCommand>) -> Result<()>
+where
+    S: tokio::io::AsyncRead + tokio::io::AsyncWrite + Unpin,
+{
+    use tokio::io::{AsyncBufReadExt as _, AsyncWriteExt as _};
+
+    let (reader, mut writer) = tokio::io::split(stream);
+    let mut lines = tokio::io::BufReader::new(reader).lines();
+
+    while let Some(line) = lines.next_line().await? {
+        let response = match serde_json::from_str(&line) {
+            Ok(ControlRequest::Status) => {
+                let (reply_tx, reply_rx) = oneshot::channel();
+                tx.send(ControlCommand::Status(reply_tx)).await?;
+                serde_json::to_string(&reply_rx.await?)?
+            }
+            Ok(ControlRequest::Reset) => {
+                tx.send(ControlCommand::Reset).await?;
+                r#"{"ok":true}"#.to_string()
+            }
+            Ok(ControlRequest::ReloadToken) => {
+                tx.send(ControlCommand::ReloadToken).await?;
+                r#"{"ok":true}"#.to_string()
+            }
+            Ok(ControlRequest::Disconnect) => {
+                tx.send(ControlCommand::Disconnect).await?;
+                r#"{"ok":true}"#.to_string()
+            }
+            Ok(ControlRequest::SetLogFilter { filter }) => {
+                tx.send(ControlCommand::SetLogFilter(filter)).await?;
+                r#"{"ok":true}"#.to_string()
+            }
+            Err(error) => serde_json::to_string(&ErrorResponse {
+                error: error.to_string(),
+            })
+            .unwrap_or_else(|_| r#"{"error":"failed to serialize error"}"#.to_string()),
+        };
+
+        writer.write_all(response.as_bytes()).await?;
+        writer.write_all(b"\n").await?;
+    }
+
+    Ok(())
 }
 
 #[derive(clap::Subcommand, Clone, Copy)]
@@ -103,6 +441,38 @@ pub fn run_only_headless_client() -> Result<()> {
     let token_env_var = cli.token.take().map(SecretString::from);
     let cli = cli;
 
+    let config_file = load_config_file(&cli.config, cli.config == default_config_path())
+        .context("Failed to load `--config` file")?;
+
+    let api_url = cli
+        .api_url
+        .clone()
+        .or_else(|| config_file.api_url.clone())
+        .unwrap_or_else(|| {
+            url::Url::parse("wss://api.firezone.dev").expect("hard-coded URL should always parse")
+        });
+    let token_path = cli
+        .token_path
+        .clone()
+        .or_else(|| config_file.token_path.clone())
+        .unwrap_or_else(default_token_path);
+    let firezone_name = cli.firezone_name.clone().or_else(|| config_file.firezone_name.clone());
+    let firezone_id = cli.firezone_id.clone().or_else(|| config_file.firezone_id.clone());
+
+    if let Some(dns_control_method) = config_file.dns_control_method.as_deref() {
+        // `DnsControlMethod::from_env` reads this below; only set it from the
+        // config file if the operator hasn't already set it in the environment.
+        if std::env::var_os("FIREZONE_DNS_CONTROL").is_none() {
+            // SAFETY: We haven't spawned any other threads, this code should be the first
+            // thing to run after entering `main` and parsing CLI args.
+            // So nobody else is reading or writing the environment.
+            #[allow(unused_unsafe)]
+            unsafe {
+                std::env::set_var("FIREZONE_DNS_CONTROL", dns_control_method);
+            }
+        }
+    }
+
     // Docs indicate that `remove_var` should actually be marked unsafe
     // SAFETY: We haven't spawned any other threads, this code should be the first
     // thing to run after entering `main` and parsing CLI args.
@@ -114,15 +484,36 @@ pub fn run_only_headless_client() -> Result<()> {
     }
     assert!(std::env::var(TOKEN_ENV_KEY).is_err());
 
-    // TODO: This might have the same issue with fatal errors not getting logged
-    // as addressed for the IPC service in PR #5216
-    let (layer, _handle) = cli
+    let log_dir = cli
         .common
         .log_dir
-        .as_deref()
-        .map(file_logger::layer)
-        .unzip();
-    setup_global_subscriber(layer);
+        .clone()
+        .or_else(|| config_file.log_dir.clone());
+
+    // TODO: This might have the same issue with fatal errors not getting logged
+    // as addressed for the IPC service in PR #5216
+    let (file_layer, _handle) = log_dir.as_deref().map(file_logger::layer).unzip();
+    // `RUST_LOG` still wins over our hard-coded default, same as it always has: operators
+    // overriding verbosity for a one-off debugging session shouldn't have that silently
+    // discarded just because we also expose `set-log-filter` over the control socket.
+    let initial_filter = std::env::var("RUST_LOG")
+        .ok()
+        .and_then(|directives| EnvFilter::try_new(directives).ok())
+        .unwrap_or_else(|| {
+            EnvFilter::try_new(DEFAULT_LOG_FILTER).expect("hard-coded default filter should parse")
+        });
+    let (filter_layer, log_filter_reload_handle) = reload::Layer::new(initial_filter);
+    // Always build the console layer ourselves and put the reloadable filter on it, not
+    // just on the optional file layer: most headless deployments are journald-logged and
+    // never set `--log-dir`, so a filter that only covers the file layer would make
+    // SIGUSR1 / the control socket's `set-log-filter` silently do nothing for them.
+    let console_layer = tracing_subscriber::fmt::layer();
+    let layer = match file_layer {
+        Some(file_layer) => console_layer.and_then(file_layer).boxed(),
+        None => console_layer.boxed(),
+    }
+    .with_filter(filter_layer);
+    setup_global_subscriber(Some(layer));
 
     tracing::info!(
         arch = std::env::consts::ARCH,
@@ -133,58 +524,75 @@ pub fn run_only_headless_client() -> Result<()> {
         .enable_all()
         .build()?;
 
-    let token = get_token(token_env_var, &cli.token_path)?.with_context(|| {
-        format!(
+    let token = get_token(token_env_var, &token_path)?.unwrap_or_else(|| {
+        tracing::error!(
             "Can't find the Firezone token in ${TOKEN_ENV_KEY} or in `{}`",
-            cli.token_path.display()
-        )
-    })?;
+            token_path.display()
+        );
+        std::process::exit(exit_code::TOKEN_NOT_FOUND);
+    });
     // TODO: Should this default to 30 days?
-    let max_partition_time = cli.common.max_partition_time.map(|d| d.into());
+    let max_partition_time = cli
+        .common
+        .max_partition_time
+        .or(config_file.max_partition_time)
+        .map(|d| d.into());
 
     // AKA "Device ID", not the Firezone slug
-    let firezone_id = match cli.firezone_id {
+    let firezone_id = match firezone_id {
         Some(id) => id,
         None => device_id::get_or_create().context("Could not get `firezone_id` from CLI, could not read it from disk, could not generate it and save it to disk")?.id,
     };
 
     let (private_key, public_key) = keypair();
+    // Kept around so a token-file reload (below) can rebuild the `LoginUrl` and
+    // reconnect without changing the Client's WireGuard identity.
     let url = LoginUrl::client(
-        cli.api_url,
+        api_url.clone(),
         &token,
-        firezone_id,
-        cli.firezone_name,
+        firezone_id.clone(),
+        firezone_name.clone(),
         public_key.to_bytes(),
     )?;
 
+    // Built once and reused for the initial connect and every reconnect.
+    let tls_client_config = build_tls_client_config(
+        cli.client_cert
+            .as_deref()
+            .or(config_file.client_cert.as_deref()),
+        cli.client_key
+            .as_deref()
+            .or(config_file.client_key.as_deref()),
+        cli.ca_cert.as_deref().or(config_file.ca_cert.as_deref()),
+    )
+    .context("Failed to build mTLS client config")?;
+
     if cli.check {
         tracing::info!("Check passed");
         return Ok(());
     }
 
     let (cb_tx, cb_rx) = mpsc::channel(10);
-    let callbacks = CallbackHandler { cb_tx };
 
     // The name matches that in `ipc_service.rs`
     let mut last_connlib_start_instant = Some(Instant::now());
-    let args = ConnectArgs {
-        udp_socket_factory: Arc::new(crate::udp_socket_factory),
-        tcp_socket_factory: Arc::new(crate::tcp_socket_factory),
-        private_key,
-        callbacks,
-    };
     let _guard = rt.enter(); // Constructing `PhoenixChannel` requires a runtime context.
-    let portal = PhoenixChannel::connect(
-        Secret::new(url),
-        get_user_agent(None, env!("CARGO_PKG_VERSION")),
-        "client",
-        (),
-        ExponentialBackoffBuilder::default()
-            .with_max_elapsed_time(max_partition_time)
-            .build(),
-        Arc::new(crate::tcp_socket_factory),
+    let mut session = connect_portal(
+        url,
+        max_partition_time,
+        tls_client_config.clone(),
+        private_key.clone(),
+        CallbackHandler {
+            cb_tx: cb_tx.clone(),
+        },
+        rt.handle().clone(),
     )?;
-    let session = Session::connect(args, portal, rt.handle().clone());
+
+    // Set by the connect-timeout and initial-auth-failure paths below. Checked after the
+    // loop so `std::process::exit` only runs once `tun_device`/`dns_controller` (dropped at
+    // the end of the `block_on` future) and `session.disconnect()` have torn things down,
+    // instead of skipping that cleanup the way exiting straight from inside the loop would.
+    let mut exit_code = None;
 
     let result = rt.block_on(async {
         let mut terminate = signals::Terminate::new()?;
@@ -211,15 +619,67 @@ pub fn run_only_headless_client() -> Result<()> {
         session.set_tun(Box::new(tun));
         session.set_dns(dns_control::system_resolvers().unwrap_or_default());
 
+        let (control_tx, mut control_rx) = mpsc::channel(10);
+        tokio::spawn({
+            let control_socket = cli.control_socket.clone();
+            async move {
+                if let Err(error) = run_control_listener(control_socket, control_tx).await {
+                    tracing::error!(?error, "Control socket listener exited");
+                }
+            }
+        });
+        let mut resource_count = 0usize;
+
+        let (token_changed_tx, mut token_changed_rx) = mpsc::channel(1);
+        let mut token_bytes = std::fs::read(&token_path).ok();
+        let _token_watcher = watch_token_file(token_path.clone(), token_changed_tx)
+            .context("Failed to watch token file for changes")?;
+
+        let mut log_filter_signal = LogFilterSignal::new()?;
+
+        let connect_deadline = cli
+            .connect_timeout
+            .map(|timeout| Instant::now() + std::time::Duration::from(timeout));
+        // Set on the first `OnSetInterfaceConfig` and never cleared, unlike
+        // `last_connlib_start_instant`, which is re-armed after a post-connect auth error.
+        let mut connected_once = false;
+
         let result = loop {
             let mut dns_changed = pin!(dns_notifier.notified().fuse());
             let mut network_changed = pin!(network_notifier.notified().fuse());
+            let mut control_cmd = pin!(control_rx.recv().fuse());
+            let mut token_changed = pin!(token_changed_rx.recv().fuse());
+            let mut usr1 = pin!(log_filter_signal.recv().fuse());
+            // Only armed until the first `OnSetInterfaceConfig`; pending forever afterwards
+            // so it never fires again once we've connected.
+            let mut connect_timeout = pin!(async move {
+                match connect_deadline {
+                    Some(deadline) if !connected_once => {
+                        tokio::time::sleep_until(deadline).await;
+                    }
+                    _ => futures::future::pending::<()>().await,
+                }
+            }
+            .fuse());
 
             let cb = futures::select! {
                 () = terminate => {
                     tracing::info!("Caught SIGINT / SIGTERM / Ctrl+C");
                     break Ok(());
                 },
+                () = connect_timeout => {
+                    tracing::error!("Timed out waiting for the initial connection to the portal");
+                    exit_code = Some(exit_code::CONNECT_TIMEOUT);
+                    break Ok(());
+                },
+                () = usr1 => {
+                    tracing::info!("Caught SIGUSR1, reloading log filter from file");
+                    match std::fs::read_to_string(&cli.log_filter_file) {
+                        Ok(filter) => reload_log_filter(&log_filter_reload_handle, filter.trim()),
+                        Err(error) => tracing::warn!(?error, "Failed to read log filter file"),
+                    }
+                    continue;
+                },
                 () = hangup => {
                     tracing::info!("Caught SIGHUP");
                     session.reset();
@@ -239,6 +699,86 @@ pub fn run_only_headless_client() -> Result<()> {
                     session.reset();
                     continue;
                 },
+                cmd = control_cmd => {
+                    match cmd {
+                        Some(ControlCommand::Status(reply)) => {
+                            let _ = reply.send(StatusResponse {
+                                connected: last_connlib_start_instant.is_none(),
+                                startup_elapsed_secs: last_connlib_start_instant.map(|i| i.elapsed().as_secs_f64()),
+                                resource_count,
+                                dns_control_method: format!("{dns_control_method:?}"),
+                            });
+                        }
+                        Some(ControlCommand::Reset) => {
+                            tracing::info!("Resetting Session due to control-socket command");
+                            session.reset();
+                        }
+                        Some(ControlCommand::ReloadToken) => {
+                            tracing::info!("Reloading token file due to control-socket command");
+                            match reload_token_if_changed(&token_path, &mut token_bytes) {
+                                Ok(Some(new_token)) => {
+                                    let new_url = LoginUrl::client(
+                                        api_url.clone(),
+                                        &new_token,
+                                        firezone_id.clone(),
+                                        firezone_name.clone(),
+                                        public_key.to_bytes(),
+                                    )?;
+                                    session = connect_portal(
+                                        new_url,
+                                        max_partition_time,
+                                        tls_client_config.clone(),
+                                        private_key.clone(),
+                                        CallbackHandler { cb_tx: cb_tx.clone() },
+                                        tokio::runtime::Handle::current(),
+                                    )?;
+                                }
+                                Ok(None) => tracing::info!("Control socket requested a token reload, but the token on disk is unchanged"),
+                                Err(error) => tracing::warn!(?error, "Failed to reload token file"),
+                            }
+                        }
+                        Some(ControlCommand::Disconnect) => {
+                            tracing::info!("Disconnecting due to control-socket command");
+                            break Ok(());
+                        }
+                        Some(ControlCommand::SetLogFilter(filter)) => {
+                            reload_log_filter(&log_filter_reload_handle, &filter);
+                        }
+                        None => tracing::error!("Control socket channel closed unexpectedly"),
+                    }
+                    continue;
+                },
+                token_changed => {
+                    let Some(()) = token_changed else {
+                        tracing::error!("Token file watch channel closed unexpectedly");
+                        continue;
+                    };
+
+                    match reload_token_if_changed(&token_path, &mut token_bytes) {
+                        Ok(Some(new_token)) => {
+                            tracing::info!("Token file changed, reconnecting with the new token");
+                            let new_url = LoginUrl::client(
+                                api_url.clone(),
+                                &new_token,
+                                firezone_id.clone(),
+                                firezone_name.clone(),
+                                public_key.to_bytes(),
+                            )?;
+                            session = connect_portal(
+                                new_url,
+                                max_partition_time,
+                                tls_client_config.clone(),
+                                private_key.clone(),
+                                CallbackHandler { cb_tx: cb_tx.clone() },
+                                tokio::runtime::Handle::current(),
+                            )?;
+                        }
+                        // Unchanged, or transiently unreadable (the existing TOCTOU case).
+                        Ok(None) => {}
+                        Err(error) => tracing::warn!(?error, "Failed to reload token file"),
+                    }
+                    continue;
+                },
                 cb = cb_rx.next() => cb.context("cb_rx unexpectedly ran empty")?,
             };
 
@@ -246,9 +786,31 @@ pub fn run_only_headless_client() -> Result<()> {
                 // TODO: Headless Client shouldn't be using messages labelled `Ipc`
                 InternalServerMsg::Ipc(IpcServerMsg::OnDisconnect {
                     error_msg,
-                    is_authentication_error: _,
-                }) => break Err(anyhow!(error_msg).context("Firezone disconnected")),
-                InternalServerMsg::Ipc(IpcServerMsg::OnUpdateResources(_)) => {
+                    is_authentication_error,
+                }) => {
+                    if is_authentication_error {
+                        if !connected_once {
+                            // Never completed the initial connection, so there's no prior
+                            // good token to fall back on: this is a fatal config error.
+                            tracing::error!(error_msg, "Authentication failed while connecting");
+                            exit_code = Some(exit_code::AUTH_FAILURE);
+                            break Ok(());
+                        }
+                        // Otherwise we were connected before, so this is likely a token we
+                        // just hot-reloaded being rejected (or the portal revoking the
+                        // current one). Mark the tunnel down for `status` and reset the
+                        // Session the same way a network change does, so a corrected token
+                        // can be picked up without a restart instead of idling forever with
+                        // a dead tunnel.
+                        tracing::warn!(error_msg, "Firezone disconnected due to an authentication error, reconnecting");
+                        last_connlib_start_instant = Some(Instant::now());
+                        session.reset();
+                        continue;
+                    }
+                    break Err(anyhow!(error_msg).context("Firezone disconnected"));
+                }
+                InternalServerMsg::Ipc(IpcServerMsg::OnUpdateResources(resources)) => {
+                    resource_count = resources.len();
                     // On every Resources update, flush DNS to mitigate <https://github.com/firezone/firezone/issues/5052>
                     dns_controller.flush()?;
                 }
@@ -260,6 +822,7 @@ pub fn run_only_headless_client() -> Result<()> {
                     dns_controller.set_dns(&dns).await?;
                     // `on_set_interface_config` is guaranteed to be called when the tunnel is completely ready
                     // <https://github.com/firezone/firezone/pull/6026#discussion_r1692297438>
+                    connected_once = true;
                     if let Some(instant) = last_connlib_start_instant.take() {
                         // `OnUpdateResources` appears to be the latest callback that happens during startup
                         tracing::info!(elapsed = ?instant.elapsed(), "Tunnel ready");
@@ -285,9 +848,137 @@ pub fn run_only_headless_client() -> Result<()> {
 
     session.disconnect();
 
+    if let Some(exit_code) = exit_code {
+        result?;
+        std::process::exit(exit_code);
+    }
+
     result
 }
 
+/// Waits for `SIGUSR1`, used to trigger a log filter reload from [`Cli::log_filter_file`]
+///
+/// Windows has no equivalent signal, so `recv` never resolves there; operators on
+/// Windows can still reload the filter through the control socket.
+struct LogFilterSignal {
+    #[cfg(unix)]
+    inner: tokio::signal::unix::Signal,
+}
+
+impl LogFilterSignal {
+    fn new() -> Result<Self> {
+        #[cfg(unix)]
+        {
+            let inner =
+                tokio::signal::unix::signal(tokio::signal::unix::SignalKind::user_defined1())
+                    .context("Failed to register SIGUSR1 handler")?;
+            Ok(Self { inner })
+        }
+        #[cfg(not(unix))]
+        Ok(Self {})
+    }
+
+    async fn recv(&mut self) {
+        #[cfg(unix)]
+        {
+            self.inner.recv().await;
+        }
+        #[cfg(not(unix))]
+        std::future::pending::<()>().await
+    }
+}
+
+/// Parse and install a new `EnvFilter`, logging (not failing) if it's malformed
+fn reload_log_filter<S>(handle: &reload::Handle<EnvFilter, S>, filter: &str)
+where
+    S: tracing::Subscriber + Send + Sync + 'static,
+{
+    let parsed = match EnvFilter::try_new(filter) {
+        Ok(parsed) => parsed,
+        Err(error) => {
+            tracing::warn!(?error, filter, "Ignoring malformed log filter");
+            return;
+        }
+    };
+
+    match handle.reload(parsed) {
+        Ok(()) => tracing::info!(filter, "Reloaded log filter"),
+        Err(error) => tracing::warn!(?error, "Failed to reload log filter"),
+    }
+}
+
+/// Watch `path` for changes, notifying `tx` (non-blocking; a full channel just drops the tick)
+///
+/// The returned watcher must be kept alive for as long as the watch should run. Only
+/// presence of an event is forwarded, so a burst of events collapses into at most one
+/// pending tick; change-detection happens in [`reload_token_if_changed`] since a token
+/// file is small and cheap to just re-read.
+fn watch_token_file(path: PathBuf, tx: mpsc::Sender<()>) -> Result<notify::RecommendedWatcher> {
+    use notify::Watcher as _;
+
+    // Secret-rotation tooling (the exact use case this feature targets) overwhelmingly
+    // replaces files atomically via rename rather than writing in place. On Linux an
+    // inotify watch on the file itself follows the old inode, not the new one, so it goes
+    // dead after the very first atomic rotation. Watch the parent directory instead and
+    // filter events down to this file's name, which survives the old inode being unlinked.
+    let parent = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+        .map(Path::to_path_buf)
+        .unwrap_or_else(|| PathBuf::from("."));
+    let file_name = path.file_name().map(|name| name.to_os_string());
+
+    let mut watcher = notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+        match event {
+            Ok(event)
+                if (event.kind.is_modify() || event.kind.is_create())
+                    && event.paths.iter().any(|p| p.file_name() == file_name.as_deref()) =>
+            {
+                let _ = tx.try_send(());
+            }
+            Ok(_) => {}
+            Err(error) => tracing::warn!(?error, "Token file watcher error"),
+        }
+    })
+    .context("Failed to create token file watcher")?;
+    watcher
+        .watch(&parent, notify::RecursiveMode::NonRecursive)
+        .with_context(|| {
+            format!(
+                "Failed to watch token file's parent directory `{}`",
+                parent.display()
+            )
+        })?;
+
+    Ok(watcher)
+}
+
+/// Re-read the token file if its on-disk bytes have changed since `last_bytes`
+///
+/// Returns `Ok(None)` if the token is unchanged or the file is transiently unreadable
+/// (the existing TOCTOU case also handled by [`read_token_file`]). Returns `Err` if the
+/// permission check fails or the new bytes don't parse as UTF-8. `last_bytes` is updated
+/// in place so repeated calls detect further changes.
+fn reload_token_if_changed(
+    path: &Path,
+    last_bytes: &mut Option<Vec<u8>>,
+) -> Result<Option<SecretString>> {
+    platform::check_token_permissions(path)?;
+
+    let Ok(bytes) = std::fs::read(path) else {
+        tracing::info!(?path, "Token file unreadable during reload, will retry on next change");
+        return Ok(None);
+    };
+
+    if last_bytes.as_ref() == Some(&bytes) {
+        return Ok(None);
+    }
+    *last_bytes = Some(bytes.clone());
+
+    let token = String::from_utf8(bytes)?.trim().to_string();
+    Ok(Some(SecretString::from(token)))
+}
+
 /// Read the token from disk if it was not in the environment
 ///
 /// # Returns
@@ -356,7 +1047,7 @@ mod tests {
         let actual = Cli::parse_from([exe_name, "--api-url", "wss://api.firez.one"]);
         assert_eq!(
             actual.api_url,
-            Url::parse("wss://api.firez.one").expect("Hard-coded URL should always be parsable")
+            Some(Url::parse("wss://api.firez.one").expect("Hard-coded URL should always be parsable"))
         );
         assert!(!actual.check);
 
@@ -364,4 +1055,30 @@ mod tests {
         assert!(actual.check);
         assert_eq!(actual.common.log_dir, Some(PathBuf::from("bogus_log_dir")));
     }
+
+    #[test]
+    fn config_file() {
+        let config: super::ConfigFile = toml::from_str(
+            r#"
+            api-url = "wss://api.firez.one"
+            firezone-id = "1234"
+            log-dir = "bogus_log_dir"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.api_url,
+            Some(Url::parse("wss://api.firez.one").expect("Hard-coded URL should always be parsable"))
+        );
+        assert_eq!(config.firezone_id, Some("1234".to_string()));
+        assert_eq!(config.log_dir, Some(PathBuf::from("bogus_log_dir")));
+        assert_eq!(config.firezone_name, None);
+    }
+
+    #[test]
+    fn no_mtls_flags_means_no_tls_override() {
+        let config = super::build_tls_client_config(None, None, None).unwrap();
+        assert!(config.is_none());
+    }
 }